@@ -0,0 +1,73 @@
+use crate::{hittable::Interval, vec3::Position, Ray};
+
+// An axis-aligned bounding box, used by `BvhNode` to cheaply reject rays
+// that can't possibly hit the objects it bounds.
+#[derive(Copy, Clone, Debug)]
+pub struct Aabb {
+    min: Position,
+    max: Position,
+}
+
+impl Aabb {
+    pub fn new(min: Position, max: Position) -> Aabb {
+        Aabb { min, max }
+    }
+
+    pub fn min(&self) -> Position {
+        self.min
+    }
+
+    pub fn max(&self) -> Position {
+        self.max
+    }
+
+    pub fn centroid(&self) -> Position {
+        (self.min + self.max) * 0.5
+    }
+
+    // The smallest box containing both `self` and `other`.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb::new(
+            Position::new(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+                self.min.z().min(other.min.z()),
+            ),
+            Position::new(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+                self.max.z().max(other.max.z()),
+            ),
+        )
+    }
+
+    pub fn hit(&self, ray: &Ray, interval: Interval) -> bool {
+        let mut t_min = interval.start;
+        let mut t_max = interval.end;
+
+        for axis in 0..3 {
+            let (min_a, max_a, origin_a, dir_a) = match axis {
+                0 => (self.min.x(), self.max.x(), ray.origin().x(), ray.direction().x()),
+                1 => (self.min.y(), self.max.y(), ray.origin().y(), ray.direction().y()),
+                _ => (self.min.z(), self.max.z(), ray.origin().z(), ray.direction().z()),
+            };
+
+            let inv_d = 1.0 / dir_a;
+            let mut t0 = (min_a - origin_a) * inv_d;
+            let mut t1 = (max_a - origin_a) * inv_d;
+
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+
+            if t_max <= t_min {
+                return false;
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    aabb::Aabb,
+    hittable::{HitRecord, Hittable, Interval},
+    vec3::Position,
+    Ray,
+};
+
+// A bounding volume hierarchy node. Replaces a linear `HittableList` scan
+// with an O(log n) tree walk by recursively splitting objects along the
+// longest axis of their enclosing box.
+pub struct BvhNode {
+    left: Box<dyn Hittable>,
+    right: Option<Box<dyn Hittable>>,
+    bbox: Aabb,
+}
+
+impl BvhNode {
+    pub fn new(mut objects: Vec<Box<dyn Hittable>>) -> BvhNode {
+        let bbox = objects
+            .iter()
+            .map(|object| object.bounding_box())
+            .reduce(|a, b| a.union(&b))
+            .expect("BvhNode requires at least one object");
+
+        if objects.len() == 1 {
+            let left = objects.pop().unwrap();
+            return BvhNode { left, right: None, bbox };
+        }
+
+        let axis = Self::longest_axis(&bbox);
+        objects.sort_by(|a, b| {
+            let ca = Self::axis_value(a.bounding_box().centroid(), axis);
+            let cb = Self::axis_value(b.bounding_box().centroid(), axis);
+            ca.partial_cmp(&cb).unwrap()
+        });
+
+        let right_objects = objects.split_off(objects.len() / 2);
+
+        let left = Self::build(objects);
+        let right = Self::build(right_objects);
+
+        BvhNode { left, right: Some(right), bbox }
+    }
+
+    fn build(mut objects: Vec<Box<dyn Hittable>>) -> Box<dyn Hittable> {
+        if objects.len() == 1 {
+            objects.pop().unwrap()
+        } else {
+            Box::new(BvhNode::new(objects))
+        }
+    }
+
+    fn longest_axis(bbox: &Aabb) -> usize {
+        let extent = bbox.max() - bbox.min();
+        if extent.x() > extent.y() && extent.x() > extent.z() {
+            0
+        } else if extent.y() > extent.z() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn axis_value(p: Position, axis: usize) -> f64 {
+        match axis {
+            0 => p.x(),
+            1 => p.y(),
+            _ => p.z(),
+        }
+    }
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, ray: &Ray, interval: Interval) -> Option<HitRecord> {
+        if !self.bbox.hit(ray, interval.clone()) {
+            return None;
+        }
+
+        let hit_left = self.left.hit(ray, interval.clone());
+        let closest_so_far = hit_left.as_ref().map_or(interval.end, |rec| rec.t());
+
+        let hit_right = self
+            .right
+            .as_ref()
+            .and_then(|right| right.hit(ray, interval.start..closest_so_far));
+
+        hit_right.or(hit_left)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        self.bbox
+    }
+}
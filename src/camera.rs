@@ -1,10 +1,10 @@
-use std::sync::{Arc, Mutex};
 use std::io::Write;
 
-use crate::{vec3::{self, Color, Direction, Position}, HittableList, Ray};
+use crate::{hittable::Hittable, vec3::{self, Color, Direction, Position}, Ray};
 
 use indicatif::ProgressBar;
-use rand::prelude::*;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_pcg::Pcg64Mcg;
 use rayon::prelude::*;
 
 pub struct Camera {
@@ -19,6 +19,9 @@ pub struct Camera {
     defocus_disk_u: Direction,
     defocus_disk_v: Direction,
     defocus_angle: f64,
+    time0: f64,
+    time1: f64,
+    seed: u64,
 }
 
 impl Camera {
@@ -32,6 +35,9 @@ impl Camera {
         vup: Direction,
         defocus_angle: f64,
         focus_dist: f64,
+        time0: f64,
+        time1: f64,
+        seed: u64,
     ) -> Camera {
         let img_height = ((img_width as f64 / aspect_ratio) as i32).max(1);
         
@@ -71,14 +77,16 @@ impl Camera {
             defocus_disk_u,
             defocus_disk_v,
             defocus_angle,
+            time0,
+            time1,
+            seed,
         };
     }
 
-    pub fn render(&self, world: &HittableList) {
+    pub fn render(&self, world: &dyn Hittable) {
         let img_width = self.img_width as usize;
         let img_height = self.img_height as usize;
         let mut img = vec![Color::default(); img_width * img_height];
-        let world = Arc::new(world);
 
         let pb = ProgressBar::new(img.len() as u64);
         pb.set_style(
@@ -95,11 +103,13 @@ impl Camera {
                 let j = i / img_width;
                 let i = i % img_width;
 
+                let mut rng = Pcg64Mcg::seed_from_u64(self.seed ^ (j * img_width + i) as u64);
+
                 let color = (0..self.sample_size)
                     .fold(Color::default(), |acc, _| {
-                        let ray = self.get_ray(i as i32, j as i32);
-                        acc + ray.color(&world, self.max_depth)
-                    }) 
+                        let ray = self.get_ray(i as i32, j as i32, &mut rng);
+                        acc + ray.color(world, self.max_depth, &mut rng)
+                    })
                     / self.sample_size as f64;
 
                 *pixel = color;
@@ -129,24 +139,30 @@ impl Camera {
         writeln!(std::io::stdout(), "{} {} {}", ir, ig, ib).unwrap();
     }
 
-    fn get_ray(&self, i: i32, j: i32) -> Ray {
-        let (u, v) = (random::<f64>() - 0.5, random::<f64>() - 0.5);
+    fn get_ray(&self, i: i32, j: i32, rng: &mut dyn RngCore) -> Ray {
+        let (u, v) = (rng.gen::<f64>() - 0.5, rng.gen::<f64>() - 0.5);
 
         let sample = self.pixel00_loc + self.pixel_delta_u * (i as f64 + u) + self.pixel_delta_v * (j as f64 + v);
 
         let origin = if self.defocus_angle > 0.0 {
-            let (p1, p2, _) = vec3::rand_in_unit_disk().all();
+            let (p1, p2, _) = vec3::rand_in_unit_disk(rng).all();
+
 
-            
             self.center + self.defocus_disk_u * p1 + self.defocus_disk_v * p2
         } else {
             self.center
         };
 
         let direction = sample - origin;
+        let time = if self.time0 < self.time1 {
+            rng.gen_range(self.time0..self.time1)
+        } else {
+            self.time0
+        };
         Ray::new(
             origin,
             direction.unit(),
+            time,
         )
     }
 }
\ No newline at end of file
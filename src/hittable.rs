@@ -1,7 +1,7 @@
 use std::{ops::Range, rc::Rc, sync::Arc};
 
 use crate::{
-    material::Material, vec3::{Direction, Position}, Ray
+    aabb::Aabb, material::Material, vec3::{Direction, Position}, Ray
 };
 
 pub type Interval = Range<f64>;
@@ -57,6 +57,47 @@ impl HitRecord {
 
 pub trait Hittable: Send + Sync {
     fn hit(&self, ray: &Ray, interval: Interval) -> Option<HitRecord>;
+
+    fn bounding_box(&self) -> Aabb;
+}
+
+// Shared quadratic-intersection test used by both `Sphere` and
+// `MovingSphere`, which only differ in the (possibly time-varying) center
+// they test against.
+fn hit_sphere(
+    center: Position,
+    radius: f64,
+    material: &Arc<dyn Material>,
+    ray: &Ray,
+    interval: Interval,
+) -> Option<HitRecord> {
+    let oc = ray.origin() - center;
+    let a = ray.direction().squared_length();
+    let half_b = oc.dot(&ray.direction());
+    let c = oc.squared_length() - radius * radius;
+    let discriminant = half_b * half_b - a * c;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let sqrtd = discriminant.sqrt();
+
+    // Find the nearest root that lies in the acceptable range
+    let mut root = (-half_b - sqrtd) / a;
+    if !interval.contains(&root) {
+        root = (-half_b + sqrtd) / a;
+        if !interval.contains(&root) {
+            return None;
+        }
+    }
+
+    let outward_normal = (ray.at(root) - center) / radius;
+    let mut res = HitRecord::new(ray.at(root), outward_normal, root, false, Arc::clone(material));
+
+    res.set_face_normal(ray, outward_normal);
+
+    Some(res)
 }
 
 // Create a sphere that implements the Hittable trait
@@ -78,39 +119,60 @@ impl Sphere {
 
 impl Hittable for Sphere {
     fn hit(&self, ray: &Ray, interval: Interval) -> Option<HitRecord> {
-        let oc = ray.origin() - self.center;
-        let a = ray.direction().squared_length();
-        let half_b = oc.dot(&ray.direction());
-        let c = oc.squared_length() - self.radius * self.radius;
-        let discriminant = half_b * half_b - a * c;
+        hit_sphere(self.center, self.radius, &self.material, ray, interval)
+    }
 
-        if discriminant < 0.0 {
-            return None;
-        }
+    fn bounding_box(&self) -> Aabb {
+        let radius = Position::new(self.radius, self.radius, self.radius);
+        Aabb::new(self.center - radius, self.center + radius)
+    }
+}
 
-        let sqrtd = discriminant.sqrt();
+// A sphere whose center moves linearly between `center0` at `time0` and
+// `center1` at `time1`, used to render motion blur.
+pub struct MovingSphere {
+    center0: Position,
+    center1: Position,
+    time0: f64,
+    time1: f64,
+    radius: f64,
+    material: Arc<dyn Material>,
+}
 
-        // Find the nearest root that lies in the acceptable range
-        let mut root = (-half_b - sqrtd) / a;
-        if !interval.contains(&root) {
-            root = (-half_b + sqrtd) / a;
-            if !interval.contains(&root) {
-                return None;
-            }
+impl MovingSphere {
+    pub fn new(
+        center0: Position,
+        center1: Position,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        material: Arc<dyn Material>,
+    ) -> MovingSphere {
+        MovingSphere {
+            center0,
+            center1,
+            time0,
+            time1,
+            radius: radius.max(0.0),
+            material,
         }
+    }
 
-        let outward_normal = (ray.at(root) - self.center) / self.radius;
-        let mut res = HitRecord::new(
-            ray.at(root),
-            outward_normal,
-            root,
-            false,
-            Arc::clone(&self.material),
-        );
+    fn center(&self, time: f64) -> Position {
+        self.center0 + (self.center1 - self.center0) * ((time - self.time0) / (self.time1 - self.time0))
+    }
+}
 
-        res.set_face_normal(ray, outward_normal);
+impl Hittable for MovingSphere {
+    fn hit(&self, ray: &Ray, interval: Interval) -> Option<HitRecord> {
+        hit_sphere(self.center(ray.time()), self.radius, &self.material, ray, interval)
+    }
 
-        Some(res)
+    fn bounding_box(&self) -> Aabb {
+        let radius = Position::new(self.radius, self.radius, self.radius);
+        let box0 = Aabb::new(self.center(self.time0) - radius, self.center(self.time0) + radius);
+        let box1 = Aabb::new(self.center(self.time1) - radius, self.center(self.time1) + radius);
+        box0.union(&box1)
     }
 }
 
@@ -128,6 +190,10 @@ impl HittableList {
     pub fn add(&mut self, object: Box<dyn Hittable>) {
         self.objects.push(object);
     }
+
+    pub fn into_objects(self) -> Vec<Box<dyn Hittable>> {
+        self.objects
+    }
 }
 
 impl Hittable for HittableList {
@@ -144,4 +210,12 @@ impl Hittable for HittableList {
 
         res
     }
+
+    fn bounding_box(&self) -> Aabb {
+        self.objects
+            .iter()
+            .map(|object| object.bounding_box())
+            .reduce(|a, b| a.union(&b))
+            .expect("bounding_box called on an empty HittableList")
+    }
 }
@@ -1,5 +1,6 @@
 use std::{rc::Rc, sync::Arc};
 
+use bvh::BvhNode;
 use camera::Camera;
 use hittable::*;
 use material::{Dielectric, Lambertian, Material, Metal};
@@ -7,6 +8,8 @@ use rand::Rng;
 use ray::*;
 use vec3::{Color, Position, Vec3};
 
+mod aabb;
+mod bvh;
 mod hittable;
 mod ray;
 mod vec3;
@@ -36,21 +39,23 @@ fn ppm_demo() {
             );
 
             if (center - Position::new(4.0, 0.2, 0.0)).length() > 0.9 {
-                let sphere_material: Arc<dyn Material> = if choose_mat < 0.8 {
-                    // diffuse
+                if choose_mat < 0.8 {
+                    // diffuse: moves straight upward over the shutter interval
                     let albedo = rand::random::<Color>().mul(&rand::random::<Color>());
-                    Arc::new(Lambertian::new(albedo))
+                    let material = Arc::new(Lambertian::new(albedo));
+                    let center1 = center + Position::new(0.0, rng.gen_range(0.0..0.5), 0.0);
+                    world.add(Box::new(MovingSphere::new(center, center1, 0.0, 1.0, 0.2, material)));
                 } else if choose_mat < 0.95 {
                     // metal
                     let albedo: Vec3<f64> = (rand::random::<Color>() + Color::new(1.0, 1.0, 1.0)) * 0.5;
                     let fuzz = rng.gen_range(0.0..0.5);
-                    Arc::new(Metal::new(albedo, fuzz))
+                    let material = Arc::new(Metal::new(albedo, fuzz));
+                    world.add(Box::new(Sphere::new(center, 0.2, material)));
                 } else {
                     // glass
-                    Arc::new(Dielectric::new(1.5))
-                };
-
-                world.add(Box::new(Sphere::new(center, 0.2, sphere_material)));
+                    let material = Arc::new(Dielectric::new(1.5));
+                    world.add(Box::new(Sphere::new(center, 0.2, material)));
+                }
             }
         }
     }
@@ -75,9 +80,13 @@ fn ppm_demo() {
         Position::new(0.0, 1.0, 0.0), 
         0.6,
         10.0,
+        0.0,
+        1.0,
+        42,
     );
 
     // Render
+    let world = BvhNode::new(world.into_objects());
     camera.render(&world);
 }
 
@@ -1,7 +1,9 @@
+use rand::{Rng, RngCore};
+
 use crate::{vec3::{self, Color}, HitRecord, Ray};
 
 pub trait Material: Send + Sync {
-    fn scatter(&self, ray: &Ray, hit_record: &HitRecord) -> Option<(Color, Ray)>;
+    fn scatter(&self, ray: &Ray, hit_record: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)>;
 }
 
 pub struct Lambertian {
@@ -15,12 +17,12 @@ impl Lambertian {
 }
 
 impl Material for Lambertian {
-    fn scatter(&self, _ray: &Ray, hit_record: &HitRecord) -> Option<(Color, Ray)> {
-        let mut scatter_direction = hit_record.normal() + vec3::rand_unit_vec();
+    fn scatter(&self, ray: &Ray, hit_record: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
+        let mut scatter_direction = hit_record.normal() + vec3::rand_unit_vec(rng);
         if scatter_direction.near_zero() {
             scatter_direction = hit_record.normal();
         }
-        let scattered = Ray::new(hit_record.p(), scatter_direction);
+        let scattered = Ray::new(hit_record.p(), scatter_direction, ray.time());
         let attenuation = self.albedo;
         Some((attenuation, scattered))
     }
@@ -38,9 +40,9 @@ impl Metal {
 }
 
 impl Material for Metal {
-    fn scatter(&self, ray: &Ray, hit_record: &HitRecord) -> Option<(Color, Ray)> {
+    fn scatter(&self, ray: &Ray, hit_record: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
         let reflected = ray.direction().unit().reflect(&hit_record.normal());
-        let scattered = Ray::new(hit_record.p(), reflected + vec3::rand_unit_vec() * self.fuzz);
+        let scattered = Ray::new(hit_record.p(), reflected + vec3::rand_unit_vec(rng) * self.fuzz, ray.time());
         let attenuation = self.albedo;
         if scattered.direction().dot(&hit_record.normal()) > 0.0 {
             Some((attenuation, scattered))
@@ -66,7 +68,7 @@ impl Dielectric {
 }
 
 impl Material for Dielectric {
-    fn scatter(&self, ray: &Ray, hit_record: &HitRecord) -> Option<(Color, Ray)> {
+    fn scatter(&self, ray: &Ray, hit_record: &HitRecord, rng: &mut dyn RngCore) -> Option<(Color, Ray)> {
         let etai_over_etat = if hit_record.front_face() {
             1.0 / self.ir
         } else {
@@ -78,13 +80,13 @@ impl Material for Dielectric {
         let sin_theta = (1.0 - cos_theta.powi(2)).sqrt();
 
         let cannot_refract = etai_over_etat * sin_theta > 1.0;
-        let direction = if cannot_refract || Self::reflectance(cos_theta, etai_over_etat) > rand::random::<f64>() {
+        let direction = if cannot_refract || Self::reflectance(cos_theta, etai_over_etat) > rng.gen::<f64>() {
             unit_direction.reflect(&hit_record.normal())
         } else {
             unit_direction.refract(&hit_record.normal(), etai_over_etat)
         };
 
-        let scattered = Ray::new(hit_record.p(), direction);
+        let scattered = Ray::new(hit_record.p(), direction, ray.time());
         let attenuation = Color::new(1.0, 1.0, 1.0);
         Some((attenuation, scattered))
     }
@@ -1,16 +1,19 @@
+use rand::RngCore;
+
 use crate::{
     hittable::Hittable,
-    vec3::{Color, Direction, Position}, HittableList,
+    vec3::{Color, Direction, Position},
 };
 
 pub struct Ray {
     origin: Position,
     direction: Direction,
+    time: f64,
 }
 
 impl Ray {
-    pub fn new(origin: Position, direction: Direction) -> Ray {
-        Ray { origin, direction }
+    pub fn new(origin: Position, direction: Direction, time: f64) -> Ray {
+        Ray { origin, direction, time }
     }
 
     pub fn at(&self, t: f64) -> Position {
@@ -25,14 +28,18 @@ impl Ray {
         self.direction
     }
 
-    pub fn color(&self, world: &HittableList, depth: i32) -> Color {
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn color(&self, world: &dyn Hittable, depth: i32, rng: &mut dyn RngCore) -> Color {
         if depth <= 0 {
             return Color::default();
         }
 
         if let Some(rec) = world.hit(self, 0.001..f64::INFINITY) {
-            if let Some((attenuation, scattered)) = rec.material().scatter(self, &rec) {
-                return attenuation.mul(&scattered.color(world, depth - 1));
+            if let Some((attenuation, scattered)) = rec.material().scatter(self, &rec, rng) {
+                return attenuation.mul(&scattered.color(world, depth - 1, rng));
             }
 
             return Color::default();
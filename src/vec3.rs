@@ -1,7 +1,8 @@
 // vec3.rs
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-use rand::{prelude::Distribution, Rng};
+use rand::{prelude::Distribution, RngCore};
+use rand_distr::{UnitDisc, UnitSphere};
 
 // Private Vec3 struct
 #[derive(Copy, Clone, Debug)]
@@ -84,39 +85,14 @@ impl Vec3<f64> {
     }
 }
 
-pub fn rand_unit_vec() -> Vec3<f64> {
-    // Create a Uniform distribution between -1.0 and 1.0
-    let between = rand::distributions::Uniform::new(-1.0, 1.0);
-    let mut rng = rand::thread_rng();
-
-    loop {
-        let vec: Vec3<f64> = Vec3::new(
-            rng.sample(between),
-            rng.sample(between),
-            rng.sample(between),
-        );
-
-        let lensq = vec.squared_length();
-
-        if 1e-160 < lensq && lensq <= 1.0 {
-            return vec / lensq.sqrt();
-        }
-    }
+pub fn rand_unit_vec(rng: &mut dyn RngCore) -> Vec3<f64> {
+    let [x, y, z] = UnitSphere.sample(rng);
+    Vec3::new(x, y, z)
 }
 
-pub fn rand_in_unit_disk() -> Vec3<f64> {
-    let between = rand::distributions::Uniform::new(-1.0, 1.0);
-    let mut rng = rand::thread_rng();
-
-    loop {
-        let vec: Vec3<f64> = Vec3::new(rng.sample(between), rng.sample(between), 0.0);
-
-        let lensq = vec.squared_length();
-
-        if lensq <= 1.0 {
-            return vec;
-        }
-    }
+pub fn rand_in_unit_disk(rng: &mut dyn RngCore) -> Vec3<f64> {
+    let [x, y] = UnitDisc.sample(rng);
+    Vec3::new(x, y, 0.0)
 }
 
 // Implement basic arithmetic operators generically